@@ -0,0 +1,275 @@
+//! Async counterpart to [`crate::comms::Tmp12x`], built on an
+//! `embedded-hal-async` SPI bus. Gated behind the `async` feature.
+#[cfg(not(feature = "osensa"))]
+use crate::comms::convert_words;
+#[cfg(feature = "osensa")]
+use crate::comms::{convert_words_osensa, OsensaReading};
+use crate::comms::{decode_raw, Calibration, SensorStatus};
+use crate::error::Error;
+use crate::ic;
+use crate::stats::ThermalStats;
+use crate::temperature::Temperature;
+use core::marker::PhantomData;
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+/// Async driver for a TMP12x-family device, mirroring [`crate::comms::Tmp12x`]
+/// but using `.await`-able SPI transactions.
+pub struct Tmp12x<SPI, IC = ic::Tmp123> {
+    spi: SPI,
+    calibration: Calibration,
+    _ic: PhantomData<IC>,
+}
+
+impl<SPI, IC> Tmp12x<SPI, IC>
+where
+    SPI: SpiDevice,
+    IC: ic::SingleDevice,
+{
+    pub fn new(spi: SPI) -> Self {
+        Self {
+            spi,
+            calibration: Calibration::default(),
+            _ic: PhantomData,
+        }
+    }
+
+    /// Create a driver with a two-point linear correction applied to every
+    /// Celsius `f64` reading, instead of the identity [`Calibration`] used by
+    /// [`Self::new`]. See [`Self::set_calibration`] for the exact scope.
+    pub fn with_calibration(spi: SPI, calibration: Calibration) -> Self {
+        Self {
+            spi,
+            calibration,
+            _ic: PhantomData,
+        }
+    }
+
+    /// Set the two-point linear correction applied to future readings
+    /// returned as a Celsius `f64` (`get_reading`, `get_reading_lossy`,
+    /// `get_osensa_reading`). [`Self::get_temperature`] intentionally does
+    /// not apply it, since `Temperature` preserves the sensor's raw,
+    /// uncorrected LSB count.
+    pub fn set_calibration(&mut self, calibration: Calibration) {
+        self.calibration = calibration;
+    }
+
+    /// Get a temperature reading in Celsius.
+    ///
+    /// Behaves identically to [`crate::comms::Tmp12x::get_reading`], reusing
+    /// the same word-decoding logic, but awaits the SPI transaction instead
+    /// of blocking on it.
+    pub async fn get_reading(&mut self) -> Result<f64, Error<SPI>> {
+        let mut words = [0u8; 2];
+        self.spi
+            .transaction(&mut [Operation::Read(&mut words)])
+            .await
+            .map_err(Error::Spi)?;
+
+        #[cfg(feature = "osensa")]
+        {
+            let (temperature, _led_current) = convert_words_osensa::<SPI>(&words)?;
+            Ok(self.calibration.apply(temperature))
+        }
+
+        #[cfg(not(feature = "osensa"))]
+        Ok(self.calibration.apply(convert_words(&words)))
+    }
+
+    /// Take a reading and feed it into `stats` in one call.
+    pub async fn read_into_stats(&mut self, stats: &mut ThermalStats) -> Result<f64, Error<SPI>> {
+        let temperature = self.get_reading().await?;
+        stats.update(temperature);
+        Ok(temperature)
+    }
+
+    /// Get a temperature reading, surfacing a fault as `f64::NAN` alongside a
+    /// [`SensorStatus`] instead of awaiting an `Err`.
+    ///
+    /// Mirrors [`crate::comms::Tmp12x::get_reading_lossy`].
+    pub async fn get_reading_lossy(&mut self) -> (f64, SensorStatus) {
+        match self.get_reading().await {
+            Ok(temperature) => (temperature, SensorStatus::Valid),
+            #[cfg(feature = "osensa")]
+            Err(Error::InvalidMeasurement) => (f64::NAN, SensorStatus::NotReady),
+            #[cfg(feature = "osensa")]
+            Err(Error::NoProbe) => (f64::NAN, SensorStatus::NoProbe),
+            #[cfg(feature = "osensa")]
+            Err(Error::DeviceError) => (f64::NAN, SensorStatus::DeviceError),
+            Err(Error::Spi(_)) => (f64::NAN, SensorStatus::SpiFault),
+        }
+    }
+
+    /// Get a temperature reading as a unit-agnostic [`Temperature`], keeping
+    /// the raw LSB count lossless instead of baking in a Celsius `f64`. This
+    /// intentionally does not apply [`Calibration`]; use [`Self::get_reading`]
+    /// for the calibrated Celsius value.
+    pub async fn get_temperature(&mut self) -> Result<Temperature, Error<SPI>> {
+        let mut words = [0u8; 2];
+        self.spi
+            .transaction(&mut [Operation::Read(&mut words)])
+            .await
+            .map_err(Error::Spi)?;
+
+        #[cfg(feature = "osensa")]
+        convert_words_osensa::<SPI>(&words)?;
+
+        Ok(Temperature::from_raw(decode_raw(&words)))
+    }
+
+    /// Get a temperature reading with LED current diagnostics (OSENSA FTX 101 only).
+    #[cfg(feature = "osensa")]
+    pub async fn get_osensa_reading(&mut self) -> Result<OsensaReading, Error<SPI>> {
+        let mut words = [0u8; 2];
+        self.spi
+            .transaction(&mut [Operation::Read(&mut words)])
+            .await
+            .map_err(Error::Spi)?;
+
+        let (temperature, led_current) = convert_words_osensa::<SPI>(&words)?;
+        Ok(OsensaReading {
+            temperature: self.calibration.apply(temperature),
+            led_current,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Calibration, SensorStatus, Tmp12x};
+    use crate::ic;
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    use embedded_hal_async::spi::{ErrorType, Operation, SpiDevice};
+
+    /// Poll a future to completion on the current thread, assuming it never
+    /// actually needs to suspend. True for [`ScriptedSpi`] below, whose
+    /// `transaction` resolves synchronously, so a real executor isn't needed
+    /// just to prove the async driver decodes a scripted reply.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        fn no_op(_: *const ()) {}
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(core::ptr::null(), &VTABLE)
+        }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, no_op, no_op, no_op);
+        let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+        let mut cx = Context::from_waker(&waker);
+
+        let mut future = future;
+        let mut future = unsafe { Pin::new_unchecked(&mut future) };
+        loop {
+            if let Poll::Ready(value) = future.as_mut().poll(&mut cx) {
+                return value;
+            }
+        }
+    }
+
+    struct ScriptedSpi {
+        words: [u8; 2],
+    }
+
+    impl ErrorType for ScriptedSpi {
+        type Error = core::convert::Infallible;
+    }
+
+    impl SpiDevice for ScriptedSpi {
+        async fn transaction(
+            &mut self,
+            operations: &mut [Operation<'_, u8>],
+        ) -> Result<(), Self::Error> {
+            if let Some(Operation::Read(buf)) = operations.first_mut() {
+                buf.copy_from_slice(&self.words);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_get_reading_decodes_scripted_reply() {
+        // 25°C, with the osensa CFM bit set so this also passes validation
+        // when the `osensa` feature is enabled.
+        let spi = ScriptedSpi { words: [0x0C, 0x84] };
+        let mut sensor: Tmp12x<_> = Tmp12x::new(spi);
+
+        assert_eq!(block_on(sensor.get_reading()).unwrap(), 25.0);
+    }
+
+    #[test]
+    fn test_get_reading_applies_calibration() {
+        // 25°C, with the osensa CFM bit set so this also passes validation
+        // when the `osensa` feature is enabled.
+        let spi = ScriptedSpi { words: [0x0C, 0x84] };
+        let mut sensor = Tmp12x::<_, ic::Tmp123>::with_calibration(
+            spi,
+            Calibration {
+                offset_celsius: 1.5,
+                gain: 2.0,
+            },
+        );
+
+        assert_eq!(block_on(sensor.get_reading()).unwrap(), 25.0 * 2.0 + 1.5);
+    }
+
+    #[test]
+    fn test_get_temperature_does_not_apply_calibration() {
+        let spi = ScriptedSpi { words: [0x0C, 0x84] };
+        let mut sensor = Tmp12x::<_, ic::Tmp123>::with_calibration(
+            spi,
+            Calibration {
+                offset_celsius: 1.5,
+                gain: 2.0,
+            },
+        );
+
+        assert_eq!(block_on(sensor.get_temperature()).unwrap().celsius(), 25.0);
+    }
+
+    #[derive(Debug)]
+    struct MockSpiError;
+
+    impl embedded_hal::spi::Error for MockSpiError {
+        fn kind(&self) -> embedded_hal::spi::ErrorKind {
+            embedded_hal::spi::ErrorKind::Other
+        }
+    }
+
+    struct FaultySpi;
+
+    impl ErrorType for FaultySpi {
+        type Error = MockSpiError;
+    }
+
+    impl SpiDevice for FaultySpi {
+        async fn transaction(&mut self, _operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+            Err(MockSpiError)
+        }
+    }
+
+    #[test]
+    fn test_get_reading_lossy_returns_spi_fault_on_error() {
+        let mut sensor: Tmp12x<_> = Tmp12x::new(FaultySpi);
+
+        let (temperature, status) = block_on(sensor.get_reading_lossy());
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::SpiFault);
+    }
+
+    #[cfg(feature = "osensa")]
+    #[test]
+    fn test_get_reading_lossy_reports_each_osensa_fault() {
+        let mut sensor: Tmp12x<_> = Tmp12x::new(ScriptedSpi { words: [0x0C, 0x80] }); // CFM low
+        let (temperature, status) = block_on(sensor.get_reading_lossy());
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::NotReady);
+
+        let mut sensor: Tmp12x<_> = Tmp12x::new(ScriptedSpi { words: [0x00, 0x00] }); // no probe
+        let (temperature, status) = block_on(sensor.get_reading_lossy());
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::NoProbe);
+
+        let mut sensor: Tmp12x<_> = Tmp12x::new(ScriptedSpi { words: [0x7F, 0xF8] }); // device error
+        let (temperature, status) = block_on(sensor.get_reading_lossy());
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::DeviceError);
+    }
+}
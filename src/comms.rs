@@ -1,7 +1,11 @@
 /// Refer to datasheet:
 /// https://www.ti.com/lit/ds/symlink/tmp121.pdf
 use crate::error::Error;
+use crate::ic;
+use crate::stats::ThermalStats;
+use crate::temperature::Temperature;
 use core::fmt::Debug;
+use core::marker::PhantomData;
 use embedded_hal::spi::{Operation, SpiDevice};
 
 /// LED current level indication for OSENSA FTX 101 sensor.
@@ -36,16 +40,85 @@ pub struct OsensaReading {
     pub led_current: LedCurrentLevel,
 }
 
-pub struct Tmp12x<SPI> {
+/// Diagnostic status returned alongside a reading by
+/// [`Tmp12x::get_reading_lossy`], following the MAX31855 integration pattern
+/// of surfacing a failed conversion as NaN and an "unknown" state rather than
+/// an error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SensorStatus {
+    /// The reading is valid.
+    Valid,
+    /// The FTX 101 conversion is not yet complete (osensa only).
+    NotReady,
+    /// No probe is detected (osensa only).
+    NoProbe,
+    /// Device error detected (osensa only).
+    DeviceError,
+    /// The SPI transaction itself failed.
+    SpiFault,
+}
+
+/// A two-point linear correction applied to a Celsius reading, as
+/// `corrected = raw * gain + offset_celsius`.
+///
+/// Lets field-deployed probes be trimmed for probe and reference-junction
+/// bias without post-processing every sample. The default is the identity
+/// correction (`gain = 1.0`, `offset_celsius = 0.0`), which leaves readings
+/// unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Calibration {
+    /// Additive correction, in degrees Celsius.
+    pub offset_celsius: f64,
+    /// Multiplicative correction.
+    pub gain: f64,
+}
+
+impl Calibration {
+    pub(crate) fn apply(&self, raw_celsius: f64) -> f64 {
+        raw_celsius * self.gain + self.offset_celsius
+    }
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Self {
+            offset_celsius: 0.0,
+            gain: 1.0,
+        }
+    }
+}
+
+/// Driver for a TMP12x-family device, generic over the IC variant `IC`
+/// (one of [`ic::Tmp121`], [`ic::Tmp123`], [`ic::Tmp124`]).
+///
+/// The temperature decode is shared across all variants; only the bus
+/// transaction used to trigger a read differs, which is why [`ic::Tmp124`]
+/// (a multi-device bus part) gets its own constructor and read method below
+/// instead of the common ones available to single-device variants.
+pub struct Tmp12x<SPI, IC = ic::Tmp123> {
     spi: SPI,
+    device_address: u8,
+    calibration: Calibration,
+    _ic: PhantomData<IC>,
 }
-impl<SPI> Debug for Tmp12x<SPI> {
+impl<SPI, IC> Debug for Tmp12x<SPI, IC> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "FlashSPI")
     }
 }
 
-fn convert_words(words: &[u8; 2]) -> f64 {
+impl<SPI, IC> Tmp12x<SPI, IC> {
+    /// Set the two-point linear correction applied to future readings
+    /// returned as a Celsius `f64` (`get_reading`, `try_get_reading`,
+    /// `get_reading_lossy`, `get_osensa_reading`). [`Self::get_temperature`]
+    /// intentionally does not apply it, since `Temperature` preserves the
+    /// sensor's raw, uncorrected LSB count.
+    pub fn set_calibration(&mut self, calibration: Calibration) {
+        self.calibration = calibration;
+    }
+}
+
+pub(crate) fn decode_raw(words: &[u8; 2]) -> i16 {
     // The Temperature Register of the TMP121 and TMP123 is
     // a 16-bit, signed read-only register that stores the output of
     // the most recent conversion. Up to 16 bits can be read to
@@ -64,12 +137,15 @@ fn convert_words(words: &[u8; 2]) -> f64 {
         true => 0b11110000_00000000u16,
         false => 0b00000000_00000000,
     };
-    let temperature = (((all_bits & 0x7FFF) >> 3) | sign_mask) as i16;
-    temperature as f64 * 0.0625
+    (((all_bits & 0x7FFF) >> 3) | sign_mask) as i16
+}
+
+pub(crate) fn convert_words(words: &[u8; 2]) -> f64 {
+    decode_raw(words) as f64 * 0.0625
 }
 
 #[cfg(feature = "osensa")]
-fn convert_words_osensa<SPI: embedded_hal::spi::SpiDevice>(words: &[u8; 2]) -> Result<(f64, LedCurrentLevel), Error<SPI>> {
+pub(crate) fn convert_words_osensa<SPI: embedded_hal::spi::ErrorType>(words: &[u8; 2]) -> Result<(f64, LedCurrentLevel), Error<SPI>> {
     // For FTX 101: Same temperature format as TMP123 but with additional bits:
     // D2: CFM (confirmation) bit - HIGH = valid, LOW = invalid
     // D1, D0: LED current level indicators
@@ -108,12 +184,30 @@ fn convert_words_osensa<SPI: embedded_hal::spi::SpiDevice>(words: &[u8; 2]) -> R
     Ok((temp_celsius, led_current))
 }
 
-impl<SPI> Tmp12x<SPI>
+impl<SPI, IC> Tmp12x<SPI, IC>
 where
     SPI: SpiDevice,
+    IC: ic::SingleDevice,
 {
     pub fn new(spi: SPI) -> Self {
-        Self { spi }
+        Self {
+            spi,
+            device_address: 0,
+            calibration: Calibration::default(),
+            _ic: PhantomData,
+        }
+    }
+
+    /// Create a driver with a two-point linear correction applied to every
+    /// Celsius `f64` reading, instead of the identity [`Calibration`] used by
+    /// [`Self::new`]. See [`Self::set_calibration`] for the exact scope.
+    pub fn with_calibration(spi: SPI, calibration: Calibration) -> Self {
+        Self {
+            spi,
+            device_address: 0,
+            calibration,
+            _ic: PhantomData,
+        }
     }
 
     /// Get a temperature reading in Celsius.
@@ -148,11 +242,76 @@ where
         {
             // For FTX 101, validate the measurement using the CFM bit
             let (temperature, _led_current) = convert_words_osensa::<SPI>(&words)?;
-            Ok(temperature)
+            Ok(self.calibration.apply(temperature))
         }
 
         #[cfg(not(feature = "osensa"))]
-        Ok(convert_words(&words))
+        Ok(self.calibration.apply(convert_words(&words)))
+    }
+
+    /// Get a temperature reading, following the `nb::Result` convention used
+    /// by the tmp006 driver.
+    ///
+    /// Behaves exactly like [`Self::get_reading`], except that when the
+    /// `osensa` feature is enabled and the FTX 101's CFM bit indicates the
+    /// conversion is not yet complete, this returns `nb::Error::WouldBlock`
+    /// instead of the hard `Error::InvalidMeasurement`, so callers can poll
+    /// it from a bare-metal scheduler without treating "not ready yet" as a
+    /// fatal error.
+    pub fn try_get_reading(&mut self) -> nb::Result<f64, Error<SPI>> {
+        match self.get_reading() {
+            Ok(temperature) => Ok(temperature),
+            #[cfg(feature = "osensa")]
+            Err(Error::InvalidMeasurement) => Err(nb::Error::WouldBlock),
+            Err(e) => Err(nb::Error::Other(e)),
+        }
+    }
+
+    /// Take a reading and feed it into `stats` in one call.
+    pub fn read_into_stats(&mut self, stats: &mut ThermalStats) -> Result<f64, Error<SPI>> {
+        let temperature = self.get_reading()?;
+        stats.update(temperature);
+        Ok(temperature)
+    }
+
+    /// Get a temperature reading, surfacing any fault as `f64::NAN` alongside
+    /// a [`SensorStatus`] instead of aborting with an `Err`.
+    ///
+    /// This keeps a continuous data stream for UIs and loggers that prefer
+    /// to render "Unknown" over crashing; the strict `Result`-based methods
+    /// remain available for control paths.
+    pub fn get_reading_lossy(&mut self) -> (f64, SensorStatus) {
+        match self.get_reading() {
+            Ok(temperature) => (temperature, SensorStatus::Valid),
+            #[cfg(feature = "osensa")]
+            Err(Error::InvalidMeasurement) => (f64::NAN, SensorStatus::NotReady),
+            #[cfg(feature = "osensa")]
+            Err(Error::NoProbe) => (f64::NAN, SensorStatus::NoProbe),
+            #[cfg(feature = "osensa")]
+            Err(Error::DeviceError) => (f64::NAN, SensorStatus::DeviceError),
+            Err(Error::Spi(_)) => (f64::NAN, SensorStatus::SpiFault),
+        }
+    }
+
+    /// Get a temperature reading as a unit-agnostic [`Temperature`].
+    ///
+    /// Unlike [`Self::get_reading`], which bakes in a Celsius `f64`, this
+    /// keeps the raw LSB count so Celsius/Fahrenheit/Kelvin conversions stay
+    /// lossless and are computed on demand. This intentionally does not
+    /// apply [`Calibration`]: correction is a float-domain adjustment, and
+    /// applying it here would mean `Temperature` no longer holds the
+    /// sensor's true raw count. Use [`Self::get_reading`] for the
+    /// calibrated Celsius value.
+    pub fn get_temperature(&mut self) -> Result<Temperature, Error<SPI>> {
+        let mut words = [0u8; 2];
+        self.spi
+            .transaction(&mut [Operation::Read(&mut words)])
+            .map_err(Error::Spi)?;
+
+        #[cfg(feature = "osensa")]
+        convert_words_osensa::<SPI>(&words)?;
+
+        Ok(Temperature::from_raw(decode_raw(&words)))
     }
 
     /// Get a temperature reading with LED current diagnostics (OSENSA FTX 101 only).
@@ -197,20 +356,242 @@ where
 
         let (temperature, led_current) = convert_words_osensa::<SPI>(&words)?;
         Ok(OsensaReading {
-            temperature,
+            temperature: self.calibration.apply(temperature),
             led_current,
         })
     }
 
 }
 
+/// The TMP124's addressing is 2 bits wide (0-3), so up to four devices can
+/// share one chip-select line — not eight, as a wider field would require a
+/// 3-bit address and a different mask below. `device_address` is masked
+/// with `& 0x03` accordingly; there is no 3-bit addressing mode to fall
+/// back to.
+impl<SPI> Tmp12x<SPI, ic::Tmp124>
+where
+    SPI: SpiDevice,
+{
+    /// Create a driver for one of up to four TMP124 devices/channels
+    /// sharing a single chip-select line, selected by the 2-bit
+    /// `device_address` (0-3).
+    pub fn new_addressed(spi: SPI, device_address: u8) -> Self {
+        Self {
+            spi,
+            device_address,
+            calibration: Calibration::default(),
+            _ic: PhantomData,
+        }
+    }
+
+    /// Create a driver for one of up to four TMP124 devices/channels with a
+    /// two-point linear correction applied to every Celsius `f64` reading,
+    /// instead of the identity [`Calibration`] used by
+    /// [`Self::new_addressed`]. See [`Self::get_temperature`] for the one
+    /// read path this does not affect.
+    pub fn with_calibration(spi: SPI, device_address: u8, calibration: Calibration) -> Self {
+        Self {
+            spi,
+            device_address,
+            calibration,
+            _ic: PhantomData,
+        }
+    }
+
+    /// Send the command byte selecting this TMP124's device/channel and read
+    /// back its 16-bit temperature register in the same SPI transaction.
+    ///
+    /// This is the TMP124's only fault source: unlike the osensa-gated
+    /// single-device path, there is no CFM bit or special error encoding to
+    /// decode, so the error type here is the bare `SPI::Error` rather than
+    /// [`Error<SPI>`] — callers can't even name a fault variant that doesn't
+    /// apply to this device.
+    fn read_words(&mut self) -> Result<[u8; 2], SPI::Error> {
+        let command = [self.device_address & 0x03];
+        let mut words = [0u8; 2];
+        self.spi
+            .transaction(&mut [Operation::Write(&command), Operation::Read(&mut words)])?;
+        Ok(words)
+    }
+
+    /// Get a temperature reading in Celsius from this TMP124's address.
+    ///
+    /// Unlike the single-device variants, the TMP124 is a bus device: a
+    /// command byte selecting the device/channel is sent ahead of the
+    /// 16-bit temperature read, both within the same SPI transaction.
+    pub fn get_reading(&mut self) -> Result<f64, Error<SPI>> {
+        let words = self.read_words().map_err(Error::Spi)?;
+        Ok(self.calibration.apply(convert_words(&words)))
+    }
+
+    /// Take a reading from this TMP124's address and feed it into `stats` in
+    /// one call.
+    pub fn read_into_stats(&mut self, stats: &mut ThermalStats) -> Result<f64, Error<SPI>> {
+        let temperature = self.get_reading()?;
+        stats.update(temperature);
+        Ok(temperature)
+    }
+
+    /// Get a temperature reading from this TMP124's address, surfacing an
+    /// SPI fault as `f64::NAN` alongside a [`SensorStatus`] instead of
+    /// aborting with an `Err`.
+    pub fn get_reading_lossy(&mut self) -> (f64, SensorStatus) {
+        match self.read_words() {
+            Ok(words) => (self.calibration.apply(convert_words(&words)), SensorStatus::Valid),
+            Err(_) => (f64::NAN, SensorStatus::SpiFault),
+        }
+    }
+
+    /// Get a temperature reading from this TMP124's address as a
+    /// unit-agnostic [`Temperature`], keeping the raw LSB count lossless.
+    /// Like the single-device variant's `get_temperature`, this intentionally
+    /// does not apply [`Calibration`]; use [`Self::get_reading`] for the
+    /// calibrated Celsius value.
+    pub fn get_temperature(&mut self) -> Result<Temperature, Error<SPI>> {
+        let words = self.read_words().map_err(Error::Spi)?;
+
+        Ok(Temperature::from_raw(decode_raw(&words)))
+    }
+}
+
+/// Over-temperature alert output mode, mirroring the LM75-style OS pin behaviour.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OsMode {
+    /// The alert de-asserts automatically once the temperature falls back
+    /// below `t_hyst`.
+    Comparator,
+    /// The alert latches once asserted and is only cleared by an explicit
+    /// call to [`ThermalWatchdog::clear_alert`].
+    Interrupt,
+}
+
+/// Number of consecutive out-of-limit readings required before the alert
+/// asserts, matching the LM75 fault queue options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FaultQueue {
+    /// Assert after a single reading at or above `t_os`.
+    One,
+    /// Assert after two consecutive readings at or above `t_os`.
+    Two,
+    /// Assert after four consecutive readings at or above `t_os`.
+    Four,
+    /// Assert after six consecutive readings at or above `t_os`.
+    Six,
+}
+
+impl FaultQueue {
+    fn threshold(self) -> u8 {
+        match self {
+            FaultQueue::One => 1,
+            FaultQueue::Two => 2,
+            FaultQueue::Four => 4,
+            FaultQueue::Six => 6,
+        }
+    }
+}
+
+/// Result of a single [`ThermalWatchdog::poll`] call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertState {
+    /// Temperature is within limits and no alert is active.
+    Normal,
+    /// The alert has just transitioned from inactive to active on this poll.
+    AlertAsserted,
+    /// The alert was already active and remains so.
+    AlertActive,
+    /// The alert has just transitioned from active to inactive on this poll.
+    AlertCleared,
+}
+
+/// Software thermal watchdog built on top of [`Tmp12x`].
+///
+/// The TMP121/TMP123/FTX101 are read-only and have no on-chip limit
+/// registers, so this wrapper reproduces the LM75-style over-temperature
+/// alert (OS) behaviour in software: a high threshold `t_os`, a hysteresis
+/// threshold `t_hyst`, an [`OsMode`], and a [`FaultQueue`] count of
+/// consecutive readings at or above `t_os` that must be reached before the
+/// alert asserts.
+pub struct ThermalWatchdog<SPI> {
+    sensor: Tmp12x<SPI>,
+    t_os: f64,
+    t_hyst: f64,
+    mode: OsMode,
+    fault_queue: FaultQueue,
+    consecutive_faults: u8,
+    alert_active: bool,
+}
+
+impl<SPI> ThermalWatchdog<SPI>
+where
+    SPI: SpiDevice,
+{
+    /// Wrap `sensor` with a thermal watchdog using the given limits.
+    pub fn new(sensor: Tmp12x<SPI>, t_os: f64, t_hyst: f64, mode: OsMode, fault_queue: FaultQueue) -> Self {
+        Self {
+            sensor,
+            t_os,
+            t_hyst,
+            mode,
+            fault_queue,
+            consecutive_faults: 0,
+            alert_active: false,
+        }
+    }
+
+    /// Take a reading and advance the alert state machine.
+    ///
+    /// The consecutive-fault counter resets to zero whenever a reading is
+    /// inside the band (below `t_os`). In [`OsMode::Comparator`] mode the
+    /// alert de-asserts once the temperature drops below `t_hyst`; in
+    /// [`OsMode::Interrupt`] mode it stays latched until [`Self::clear_alert`]
+    /// is called.
+    pub fn poll(&mut self) -> Result<AlertState, Error<SPI>> {
+        let temperature = self.sensor.get_reading()?;
+
+        if temperature >= self.t_os {
+            self.consecutive_faults = self.consecutive_faults.saturating_add(1);
+
+            if !self.alert_active && self.consecutive_faults >= self.fault_queue.threshold() {
+                self.alert_active = true;
+                return Ok(AlertState::AlertAsserted);
+            }
+        } else {
+            self.consecutive_faults = 0;
+
+            if self.alert_active && self.mode == OsMode::Comparator && temperature < self.t_hyst {
+                self.alert_active = false;
+                return Ok(AlertState::AlertCleared);
+            }
+        }
+
+        if self.alert_active {
+            Ok(AlertState::AlertActive)
+        } else {
+            Ok(AlertState::Normal)
+        }
+    }
+
+    /// Clear a latched alert (used in [`OsMode::Interrupt`] mode) and reset
+    /// the consecutive-fault counter.
+    pub fn clear_alert(&mut self) {
+        self.alert_active = false;
+        self.consecutive_faults = 0;
+    }
+}
+
 #[cfg(test)]
 mod test {
+    extern crate std;
+
     use super::convert_words;
     #[cfg(feature = "osensa")]
-    use super::{convert_words_osensa, LedCurrentLevel};
+    use super::{convert_words_osensa, LedCurrentLevel, SensorStatus, Tmp12x};
     #[cfg(feature = "osensa")]
     use crate::error::Error;
+    #[cfg(not(feature = "osensa"))]
+    use super::{AlertState, Calibration, FaultQueue, OsMode, SensorStatus, ThermalWatchdog, Tmp12x};
+    #[cfg(not(feature = "osensa"))]
+    use crate::ic;
 
     #[test]
     fn test_word_conversion() {
@@ -289,4 +670,285 @@ mod test {
         assert_eq!(result.0, -25.0);
         assert_eq!(result.1, LedCurrentLevel::Range1000To2000);
     }
+
+    #[cfg(feature = "osensa")]
+    #[test]
+    fn test_try_get_reading_returns_would_block_when_cfm_bit_is_low() {
+        use core::convert::Infallible;
+        struct ScriptedSpi;
+        impl embedded_hal::spi::ErrorType for ScriptedSpi {
+            type Error = Infallible;
+        }
+        impl embedded_hal::spi::SpiDevice for ScriptedSpi {
+            fn transaction(&mut self, operations: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+                if let Some(embedded_hal::spi::Operation::Read(buf)) = operations.first_mut() {
+                    buf.copy_from_slice(&[0x0C, 0x80]); // CFM bit (D2) clear: not ready
+                }
+                Ok(())
+            }
+        }
+
+        let mut sensor: Tmp12x<_> = Tmp12x::new(ScriptedSpi);
+
+        assert!(matches!(sensor.try_get_reading(), Err(nb::Error::WouldBlock)));
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    struct ScriptedSpi {
+        words: std::vec::Vec<[u8; 2]>,
+        next: usize,
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    impl ScriptedSpi {
+        fn new(readings: &[f64]) -> Self {
+            let words = readings
+                .iter()
+                .map(|&temp| (((temp / 0.0625) as i16) << 3).to_be_bytes())
+                .collect();
+            Self { words, next: 0 }
+        }
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    impl embedded_hal::spi::ErrorType for ScriptedSpi {
+        type Error = core::convert::Infallible;
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    impl embedded_hal::spi::SpiDevice for ScriptedSpi {
+        fn transaction(&mut self, operations: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+            if let Some(embedded_hal::spi::Operation::Read(buf)) = operations.first_mut() {
+                buf.copy_from_slice(&self.words[self.next]);
+                self.next += 1;
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_thermal_watchdog_comparator_mode() {
+        // fault_queue = Two, t_os = 50.0, t_hyst = 45.0
+        let spi = ScriptedSpi::new(&[40.0, 55.0, 55.0, 55.0, 44.0]);
+        let mut watchdog = ThermalWatchdog::new(Tmp12x::new(spi), 50.0, 45.0, OsMode::Comparator, FaultQueue::Two);
+
+        assert_eq!(watchdog.poll().unwrap(), AlertState::Normal);
+        // First reading above t_os only counts as fault 1 of 2
+        assert_eq!(watchdog.poll().unwrap(), AlertState::Normal);
+        assert_eq!(watchdog.poll().unwrap(), AlertState::AlertAsserted);
+        assert_eq!(watchdog.poll().unwrap(), AlertState::AlertActive);
+        // Drops below t_hyst, so the alert clears itself in Comparator mode
+        assert_eq!(watchdog.poll().unwrap(), AlertState::AlertCleared);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_thermal_watchdog_interrupt_mode_requires_manual_clear() {
+        let spi = ScriptedSpi::new(&[55.0, 55.0, 20.0, 20.0]);
+        let mut watchdog = ThermalWatchdog::new(Tmp12x::new(spi), 50.0, 45.0, OsMode::Interrupt, FaultQueue::Two);
+
+        assert_eq!(watchdog.poll().unwrap(), AlertState::Normal);
+        assert_eq!(watchdog.poll().unwrap(), AlertState::AlertAsserted);
+        // Temperature falling below t_hyst does not clear a latched alert in Interrupt mode
+        assert_eq!(watchdog.poll().unwrap(), AlertState::AlertActive);
+
+        watchdog.clear_alert();
+        assert_eq!(watchdog.poll().unwrap(), AlertState::Normal);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    struct RecordingSpi {
+        expected_command: u8,
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    impl embedded_hal::spi::ErrorType for RecordingSpi {
+        type Error = core::convert::Infallible;
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    impl embedded_hal::spi::SpiDevice for RecordingSpi {
+        fn transaction(&mut self, operations: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+            if let [embedded_hal::spi::Operation::Write(command), embedded_hal::spi::Operation::Read(buf)] = operations {
+                assert_eq!(command, &[self.expected_command]);
+                buf.copy_from_slice(&[0x0C, 0x80]); // 25.0°C
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_tmp124_sends_device_address_before_reading() {
+        let spi = RecordingSpi { expected_command: 2 };
+        let mut sensor = Tmp12x::<_, ic::Tmp124>::new_addressed(spi, 2);
+
+        assert_eq!(sensor.get_reading().unwrap(), 25.0);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_try_get_reading_matches_get_reading_when_ready() {
+        let spi = ScriptedSpi::new(&[25.0]);
+        let mut sensor: Tmp12x<_> = Tmp12x::new(spi);
+
+        assert_eq!(nb::block!(sensor.try_get_reading()).unwrap(), 25.0);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_calibration_corrects_readings() {
+        let spi = ScriptedSpi::new(&[25.0]);
+        let mut sensor = Tmp12x::<_, ic::Tmp123>::with_calibration(
+            spi,
+            Calibration {
+                offset_celsius: 1.5,
+                gain: 2.0,
+            },
+        );
+
+        assert_eq!(sensor.get_reading().unwrap(), 25.0 * 2.0 + 1.5);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_get_temperature_does_not_apply_calibration() {
+        let spi = ScriptedSpi::new(&[25.0]);
+        let mut sensor = Tmp12x::<_, ic::Tmp123>::with_calibration(
+            spi,
+            Calibration {
+                offset_celsius: 1.5,
+                gain: 2.0,
+            },
+        );
+
+        // Unlike get_reading(), tested above to apply calibration,
+        // get_temperature() intentionally bypasses it.
+        assert_eq!(sensor.get_temperature().unwrap().celsius(), 25.0);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_tmp124_get_temperature_decodes_scripted_reply() {
+        let spi = RecordingSpi { expected_command: 2 };
+        let mut sensor = Tmp12x::<_, ic::Tmp124>::new_addressed(spi, 2);
+
+        assert_eq!(sensor.get_temperature().unwrap().celsius(), 25.0);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_default_calibration_is_identity() {
+        assert_eq!(Calibration::default(), Calibration { offset_celsius: 0.0, gain: 1.0 });
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_read_into_stats_updates_accumulator() {
+        let spi = ScriptedSpi::new(&[20.0, 30.0]);
+        let mut sensor: Tmp12x<_> = Tmp12x::new(spi);
+        let mut stats = crate::stats::ThermalStats::new();
+
+        assert_eq!(sensor.read_into_stats(&mut stats).unwrap(), 20.0);
+        assert_eq!(sensor.read_into_stats(&mut stats).unwrap(), 30.0);
+
+        assert_eq!(stats.min(), Some(20.0));
+        assert_eq!(stats.max(), Some(30.0));
+        assert_eq!(stats.sample_count(), 2);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_get_reading_lossy_returns_valid_on_success() {
+        let spi = ScriptedSpi::new(&[25.0]);
+        let mut sensor: Tmp12x<_> = Tmp12x::new(spi);
+
+        assert_eq!(sensor.get_reading_lossy(), (25.0, SensorStatus::Valid));
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[derive(Debug)]
+    struct MockSpiError;
+
+    #[cfg(not(feature = "osensa"))]
+    impl embedded_hal::spi::Error for MockSpiError {
+        fn kind(&self) -> embedded_hal::spi::ErrorKind {
+            embedded_hal::spi::ErrorKind::Other
+        }
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    struct FaultySpi;
+
+    #[cfg(not(feature = "osensa"))]
+    impl embedded_hal::spi::ErrorType for FaultySpi {
+        type Error = MockSpiError;
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    impl embedded_hal::spi::SpiDevice for FaultySpi {
+        fn transaction(&mut self, _operations: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+            Err(MockSpiError)
+        }
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_get_reading_lossy_returns_spi_fault_on_error() {
+        let mut sensor: Tmp12x<_> = Tmp12x::new(FaultySpi);
+
+        let (temperature, status) = sensor.get_reading_lossy();
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::SpiFault);
+    }
+
+    #[cfg(not(feature = "osensa"))]
+    #[test]
+    fn test_tmp124_get_reading_lossy_returns_spi_fault_on_error() {
+        let mut sensor = Tmp12x::<_, ic::Tmp124>::new_addressed(FaultySpi, 2);
+
+        let (temperature, status) = sensor.get_reading_lossy();
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::SpiFault);
+    }
+
+    #[cfg(feature = "osensa")]
+    #[test]
+    fn test_get_reading_lossy_reports_each_osensa_fault() {
+        use core::convert::Infallible;
+
+        struct ScriptedSpi {
+            words: [u8; 2],
+        }
+        impl embedded_hal::spi::ErrorType for ScriptedSpi {
+            type Error = Infallible;
+        }
+        impl embedded_hal::spi::SpiDevice for ScriptedSpi {
+            fn transaction(&mut self, operations: &mut [embedded_hal::spi::Operation<'_, u8>]) -> Result<(), Self::Error> {
+                if let Some(embedded_hal::spi::Operation::Read(buf)) = operations.first_mut() {
+                    buf.copy_from_slice(&self.words);
+                }
+                Ok(())
+            }
+        }
+
+        let mut sensor: Tmp12x<_> = Tmp12x::new(ScriptedSpi { words: [0x0C, 0x80] }); // CFM low
+        let (temperature, status) = sensor.get_reading_lossy();
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::NotReady);
+
+        let mut sensor: Tmp12x<_> = Tmp12x::new(ScriptedSpi { words: [0x00, 0x00] }); // no probe
+        let (temperature, status) = sensor.get_reading_lossy();
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::NoProbe);
+
+        let mut sensor: Tmp12x<_> = Tmp12x::new(ScriptedSpi { words: [0x7F, 0xF8] }); // device error
+        let (temperature, status) = sensor.get_reading_lossy();
+        assert!(temperature.is_nan());
+        assert_eq!(status, SensorStatus::DeviceError);
+
+        let mut sensor: Tmp12x<_> = Tmp12x::new(ScriptedSpi { words: [0x0C, 0x84] }); // valid
+        assert_eq!(sensor.get_reading_lossy(), (25.0, SensorStatus::Valid));
+    }
 }
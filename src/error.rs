@@ -1,12 +1,15 @@
 use core::fmt::{self, Debug};
 use defmt::{Format, Formatter};
-use embedded_hal::spi::SpiDevice;
+use embedded_hal::spi::ErrorType;
 
 /// The error type used by this library.
 ///
 /// This can encapsulate an SPI or GPIO error, and adds its own protocol errors
 /// on top of that.
-pub enum Error<SPI: SpiDevice> {
+///
+/// Generic over `SPI: ErrorType` rather than the full `SpiDevice` trait so
+/// that it can also wrap errors from an `embedded-hal-async` SPI bus.
+pub enum Error<SPI: ErrorType> {
     /// An SPI transfer failed.
     Spi(SPI::Error),
 
@@ -31,7 +34,7 @@ pub enum Error<SPI: SpiDevice> {
     DeviceError,
 }
 
-impl<SPI: SpiDevice> Format for Error<SPI>
+impl<SPI: ErrorType> Format for Error<SPI>
 where
     SPI::Error: Debug,
 {
@@ -47,7 +50,7 @@ where
         }
     }
 }
-impl<SPI: SpiDevice> Debug for Error<SPI>
+impl<SPI: ErrorType> Debug for Error<SPI>
 where
     SPI::Error: Debug,
 {
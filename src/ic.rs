@@ -0,0 +1,24 @@
+//! Marker types identifying which TMP12x device variant a [`crate::comms::Tmp12x`]
+//! is wired up to, following the zero-sized marker pattern used by the `lm75`
+//! driver crate.
+
+/// Marker for the TMP121.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Tmp121;
+
+/// Marker for the TMP123.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Tmp123;
+
+/// Marker for the TMP124, a bus device selected by a 2-bit device/channel
+/// address plus a leading command frame, letting multiple TMP124s share one
+/// chip select line.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Tmp124;
+
+/// Implemented by marker types for parts that are read directly with no
+/// device address, i.e. everything except the bus-addressed [`Tmp124`].
+pub trait SingleDevice {}
+
+impl SingleDevice for Tmp121 {}
+impl SingleDevice for Tmp123 {}
@@ -0,0 +1,14 @@
+//! Platform-agnostic driver for the TI TMP121/TMP123/TMP124 SPI temperature
+//! sensors, with optional support for the OSENSA FTX 101 fiber-optic probe.
+//!
+//! Built on top of `embedded-hal`'s [`SpiDevice`](embedded_hal::spi::SpiDevice) trait,
+//! so it works with any HAL implementation.
+#![no_std]
+
+#[cfg(feature = "async")]
+pub mod asynch;
+pub mod comms;
+pub mod error;
+pub mod ic;
+pub mod stats;
+pub mod temperature;
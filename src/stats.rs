@@ -0,0 +1,105 @@
+//! Running min/max/critical statistics accumulator for temperature readings.
+
+/// Running min/max/last/critical-latch accumulator fed by successive
+/// temperature readings, mirroring the current/min/max/critical values
+/// exposed by hwmon-backed monitoring tools.
+///
+/// Feed it with [`Self::update`], or use
+/// [`crate::comms::Tmp12x::read_into_stats`] to take a reading and update it
+/// in one call.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ThermalStats {
+    min: Option<f64>,
+    max: Option<f64>,
+    last: Option<f64>,
+    sample_count: u32,
+    critical: Option<f64>,
+    critical_exceeded: bool,
+}
+
+impl ThermalStats {
+    /// Create an empty accumulator with no critical threshold set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the critical threshold latched by [`Self::critical_exceeded`].
+    pub fn set_critical(&mut self, critical: f64) {
+        self.critical = Some(critical);
+    }
+
+    /// Feed a successful reading into the accumulator.
+    pub fn update(&mut self, temp: f64) {
+        self.min = Some(self.min.map_or(temp, |min| min.min(temp)));
+        self.max = Some(self.max.map_or(temp, |max| max.max(temp)));
+        self.last = Some(temp);
+        self.sample_count += 1;
+
+        if let Some(critical) = self.critical {
+            if temp >= critical {
+                self.critical_exceeded = true;
+            }
+        }
+    }
+
+    /// The lowest temperature seen so far, if any readings have been taken.
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// The highest temperature seen so far, if any readings have been taken.
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// The most recent reading, if any readings have been taken.
+    pub fn last(&self) -> Option<f64> {
+        self.last
+    }
+
+    /// The number of readings fed into this accumulator.
+    pub fn sample_count(&self) -> u32 {
+        self.sample_count
+    }
+
+    /// Whether a reading has ever reached or exceeded the critical threshold
+    /// set with [`Self::set_critical`]. Latches for post-mortem analysis
+    /// after a probe over-temperatures; it is never cleared automatically.
+    pub fn critical_exceeded(&self) -> bool {
+        self.critical_exceeded
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ThermalStats;
+
+    #[test]
+    fn test_tracks_min_max_last_and_sample_count() {
+        let mut stats = ThermalStats::new();
+        stats.update(25.0);
+        stats.update(30.0);
+        stats.update(20.0);
+
+        assert_eq!(stats.min(), Some(20.0));
+        assert_eq!(stats.max(), Some(30.0));
+        assert_eq!(stats.last(), Some(20.0));
+        assert_eq!(stats.sample_count(), 3);
+    }
+
+    #[test]
+    fn test_critical_latch_stays_set_once_exceeded() {
+        let mut stats = ThermalStats::new();
+        stats.set_critical(50.0);
+
+        stats.update(40.0);
+        assert!(!stats.critical_exceeded());
+
+        stats.update(55.0);
+        assert!(stats.critical_exceeded());
+
+        // The latch does not clear even once readings drop back down
+        stats.update(25.0);
+        assert!(stats.critical_exceeded());
+    }
+}
@@ -0,0 +1,58 @@
+//! A unit-agnostic temperature reading.
+use defmt::Format;
+
+/// A temperature reading from a TMP12x-family device.
+///
+/// Wraps the raw 0.0625°C-per-LSB count returned by the sensor so that
+/// conversions to a particular scale are computed on demand, rather than
+/// baking one scale into the reading up front.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Temperature(i16);
+
+impl Temperature {
+    /// Wrap a raw LSB count (0.0625°C per LSB).
+    pub(crate) fn from_raw(raw: i16) -> Self {
+        Self(raw)
+    }
+
+    /// The raw LSB count, as returned by the sensor.
+    pub fn raw(self) -> i16 {
+        self.0
+    }
+
+    /// Temperature in degrees Celsius.
+    pub fn celsius(self) -> f64 {
+        self.0 as f64 * 0.0625
+    }
+
+    /// Temperature in degrees Fahrenheit.
+    pub fn fahrenheit(self) -> f64 {
+        self.celsius() * 9.0 / 5.0 + 32.0
+    }
+
+    /// Temperature in Kelvin.
+    pub fn kelvin(self) -> f64 {
+        self.celsius() + 273.15
+    }
+}
+
+impl Format for Temperature {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "{}°C", self.celsius())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Temperature;
+
+    #[test]
+    fn test_unit_conversions() {
+        // 25°C = 0x0190 raw (400 * 0.0625 = 25.0)
+        let temperature = Temperature::from_raw(400);
+        assert_eq!(temperature.raw(), 400);
+        assert_eq!(temperature.celsius(), 25.0);
+        assert_eq!(temperature.fahrenheit(), 77.0);
+        assert_eq!(temperature.kelvin(), 298.15);
+    }
+}